@@ -0,0 +1,227 @@
+use std::ops::Range;
+
+use ghost_cell::GhostToken;
+
+use crate::rbtree::IndexedRBTree;
+
+/// Which backing buffer a [`Piece`] draws its bytes from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Buffer {
+    Original,
+    Add,
+}
+
+/// A contiguous run of bytes in one of the two backing buffers. The piece
+/// tree never stores text directly, only `(buffer, start, len)` triples
+/// indexing into whichever buffer is named.
+#[derive(Clone, Copy, Debug)]
+struct Piece {
+    buffer: Buffer,
+    start: usize,
+    len: usize,
+}
+
+impl Piece {
+    fn text<'b>(&self, original: &'b str, add: &'b str) -> &'b str {
+        let source = match self.buffer {
+            Buffer::Original => original,
+            Buffer::Add => add,
+        };
+        &source[self.start..self.start + self.len]
+    }
+
+    /// Splits this piece `at` bytes into its span, returning the prefix and
+    /// suffix halves as two pieces of the same buffer. Passed to
+    /// [`IndexedRBTree::split_at`] as its `split_value` closure.
+    fn split(self, at: usize) -> (Piece, Piece) {
+        (
+            Piece {
+                buffer: self.buffer,
+                start: self.start,
+                len: at,
+            },
+            Piece {
+                buffer: self.buffer,
+                start: self.start + at,
+                len: self.len - at,
+            },
+        )
+    }
+}
+
+/// A text buffer backed by an immutable `original` snapshot and an
+/// append-only `add` buffer, indexed by an [`IndexedRBTree`] of [`Piece`]s
+/// so that inserts and deletes split and join the tree in O(log n) in the
+/// number of pieces rather than O(n) in the document length. Reads walk the
+/// pieces in order from the start of the document.
+pub struct PieceTable<'a> {
+    original: String,
+    add: String,
+    pieces: IndexedRBTree<'a, Piece>,
+}
+
+impl<'a> PieceTable<'a> {
+    /// Builds a table whose initial content is `original`.
+    pub fn new(original: String, token: &mut GhostToken<'a>) -> PieceTable<'a> {
+        let len = original.len();
+        let mut pieces = IndexedRBTree::new();
+        if len > 0 {
+            pieces.insert(
+                Piece {
+                    buffer: Buffer::Original,
+                    start: 0,
+                    len,
+                },
+                len,
+                0,
+                token,
+            );
+        }
+        PieceTable {
+            original,
+            add: String::new(),
+            pieces,
+        }
+    }
+
+    /// The document's length in bytes.
+    pub fn len(&self, token: &GhostToken<'a>) -> usize {
+        self.pieces.size(token)
+    }
+
+    pub fn is_empty(&self, token: &GhostToken<'a>) -> bool {
+        self.len(token) == 0
+    }
+
+    /// Inserts `text` so that it begins at byte offset `index`, clamped to
+    /// the document's current length.
+    ///
+    /// `text` is appended to the append-only `add` buffer, and a new piece
+    /// covering it is positionally inserted into the piece tree, splitting
+    /// whichever existing piece straddles `index` into two. Appending at
+    /// the document's current end is the common case for an editor, so it
+    /// is special-cased: if the rightmost piece already covers the tail of
+    /// `add`, its span is grown in place via
+    /// [`IndexedRBTree::grow_back`] instead of inserting a new node.
+    pub fn insert(&mut self, index: usize, text: &str, token: &mut GhostToken<'a>) {
+        if text.is_empty() {
+            return;
+        }
+
+        let total = self.len(token);
+        let index = index.min(total);
+        let start = self.add.len();
+
+        let extends_last = index == total
+            && self
+                .pieces
+                .back(token)
+                .map(|last| last.buffer == Buffer::Add && last.start + last.len == start)
+                .unwrap_or(false);
+
+        self.add.push_str(text);
+
+        if extends_last {
+            let last = self.pieces.grow_back(text.len(), token).unwrap();
+            last.len += text.len();
+            return;
+        }
+
+        let piece = Piece {
+            buffer: Buffer::Add,
+            start,
+            len: text.len(),
+        };
+
+        if index == total {
+            self.pieces.insert(piece, text.len(), index, token);
+            return;
+        }
+
+        let right = self.pieces.split_at(index, Piece::split, token);
+        self.pieces.insert(piece, text.len(), index, token);
+        self.pieces.concat(right, token);
+    }
+
+    /// Removes the bytes in `range`, clamped to the document's bounds.
+    ///
+    /// Splits the piece tree at both ends of `range` and drops the middle
+    /// piece run, then glues the two remaining halves back together.
+    pub fn delete(&mut self, range: Range<usize>, token: &mut GhostToken<'a>) {
+        let total = self.len(token);
+        let start = range.start.min(total);
+        let end = range.end.min(total).max(start);
+        if start == end {
+            return;
+        }
+
+        let mut middle = self.pieces.split_at(start, Piece::split, token);
+        let right = middle.split_at(end - start, Piece::split, token);
+        self.pieces.concat(right, token);
+    }
+
+    /// Reads the bytes in `range`, clamped to the document's bounds.
+    pub fn read(&self, range: Range<usize>, token: &GhostToken<'a>) -> String {
+        let total = self.len(token);
+        let start = range.start.min(total);
+        let end = range.end.min(total).max(start);
+        if start == end {
+            return String::new();
+        }
+
+        let mut out = String::with_capacity(end - start);
+        let mut offset = 0;
+        for piece in self.pieces.iter(token) {
+            if offset >= end {
+                break;
+            }
+            let piece_start = offset;
+            let piece_end = offset + piece.len;
+            offset = piece_end;
+
+            if piece_end <= start {
+                continue;
+            }
+
+            let lo = start.saturating_sub(piece_start);
+            let hi = end.min(piece_end) - piece_start;
+            out.push_str(&piece.text(&self.original, &self.add)[lo..hi]);
+        }
+        out
+    }
+
+    /// The document's full contents.
+    pub fn to_string(&self, token: &GhostToken<'a>) -> String {
+        self.read(0..self.len(token), token)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn insert_at_end_grows_last_piece_in_place() {
+        GhostToken::new(|mut token| {
+            let mut pt = PieceTable::new("hello".to_string(), &mut token);
+            pt.insert(5, " world", &mut token);
+            assert_eq!(pt.len(&token), 11);
+            assert_eq!(pt.to_string(&token), "hello world");
+            assert_eq!(pt.pieces.len(), 2);
+
+            pt.insert(11, "!", &mut token);
+            assert_eq!(pt.len(&token), 12);
+            assert_eq!(pt.to_string(&token), "hello world!");
+            assert_eq!(pt.pieces.len(), 2);
+        });
+    }
+
+    #[test]
+    fn insert_in_middle_splits_the_straddling_piece() {
+        GhostToken::new(|mut token| {
+            let mut pt = PieceTable::new("hello world".to_string(), &mut token);
+            pt.insert(5, ",", &mut token);
+            assert_eq!(pt.to_string(&token), "hello, world");
+        });
+    }
+}
@@ -21,6 +21,7 @@ struct Node<'a, T> {
     value: T,
     flags: NodeFlags,
     size: usize,
+    count: usize,
     weight: usize,
     parent: Option<StaticRc<GhostCell<'a, Node<'a, T>>, 1, 3>>,
     left: Option<StaticRc<GhostCell<'a, Node<'a, T>>, 1, 3>>,
@@ -38,6 +39,7 @@ impl<'a, T> Node<'a, T> {
             value,
             flags: NodeFlags::empty(),
             size: weight,
+            count: 1,
             weight,
             parent: None,
             left: None,
@@ -58,6 +60,16 @@ impl<'a, T> Node<'a, T> {
         self.flags.contains(NodeFlags::BLACK)
     }
 
+    #[inline]
+    fn set_black(&mut self) {
+        self.flags.insert(NodeFlags::BLACK);
+    }
+
+    #[inline]
+    fn set_red(&mut self) {
+        self.flags.remove(NodeFlags::BLACK);
+    }
+
     #[inline]
     fn left(&self) -> Option<&StaticRc<GhostCell<'a, Node<'a, T>>, 1, 3>> {
         if self.flags.contains(NodeFlags::HAS_LEFT) {
@@ -84,6 +96,12 @@ impl<'a, T> Node<'a, T> {
         }
     }
 
+    /// Returns the parent of this node, or `None` if this node is the root.
+    #[inline]
+    fn parent(&self) -> Option<&StaticRc<GhostCell<'a, Node<'a, T>>, 1, 3>> {
+        self.parent.as_ref()
+    }
+
     #[inline]
     fn set_right(
         &mut self,
@@ -139,11 +157,54 @@ impl<'a, T> Node<'a, T> {
         p.flags.toggle(NodeFlags::HAS_RIGHT);
     }
 
-    fn drop_recursive(
-        node: &StaticRc<GhostCell<'a, Node<'a, T>>, 1, 3>,
-        token: &mut GhostToken<'a>,
-    ) {
-        todo!()
+    /// Iteratively tears down the subtree rooted at `node`'s down-share,
+    /// depth-first, post-order: an explicit stack keeps peak auxiliary
+    /// memory at O(height) instead of growing a native call frame per
+    /// level, which would overflow the stack on a deep or degenerate tree.
+    ///
+    /// Each stack entry pairs a node's down-share with whether its
+    /// children have been detached yet. On first visit, any real children
+    /// are detached via `detach_left`/`detach_right` and pushed ahead of
+    /// the node itself so they are freed first; by the time a node is
+    /// popped for its second visit, both its `left` and `right` fields
+    /// hold only its own idle self-shares, so stashing the down-share in
+    /// its otherwise-unused `parent` slot lines up all three of its own
+    /// thirds for `join_rc`, exactly as `excise` does for the tree root.
+    fn drop_recursive(node: NodeRc<'a, T>) {
+        let mut stack: Vec<(NodeRc<'a, T>, bool)> = vec![(node, false)];
+
+        while let Some((down, ready)) = stack.pop() {
+            let ptr = down.as_ptr();
+
+            if !ready {
+                stack.push((down, true));
+                // SAFETY: `down` is the only share pointing down at this
+                // cell, so reading its child slots through a raw pointer
+                // here cannot alias another live reference.
+                unsafe {
+                    if (*ptr).left().is_some() {
+                        stack.push((detach_left(ptr), false));
+                    }
+                    if (*ptr).right().is_some() {
+                        stack.push((detach_right(ptr), false));
+                    }
+                }
+                continue;
+            }
+
+            // SAFETY: both children, if any, were detached and freed
+            // above, so `ptr`'s `left`/`right` fields now hold only its
+            // own two idle self-shares. `parent` is `None` (either `ptr`
+            // was the tree root, or `detach_left`/`detach_right` already
+            // took it when detaching `ptr` from its own parent), so
+            // stashing `down` there reassembles all three of `ptr`'s own
+            // thirds for `join_rc`.
+            unsafe {
+                (*ptr).parent = Some(down);
+                let whole = (*ptr).join_rc();
+                drop(GhostCell::into_inner(StaticRc::into_inner(whole)));
+            }
+        }
     }
 }
 
@@ -172,6 +233,13 @@ impl<'a, T> IndexedRBTree<'a, T> {
         self.len == 0
     }
 
+    /// The sum of every element's `weight`, e.g. the total length in bytes
+    /// of a document backed by a `PieceTable`'s piece tree. Distinct from
+    /// [`len`](Self::len), which counts elements rather than weight.
+    pub fn size(&self, token: &GhostToken<'a>) -> usize {
+        self.root.as_ref().map(|r| r.borrow(token).size).unwrap_or(0)
+    }
+
     pub fn front<'t>(&'t self, token: &'t GhostToken<'a>) -> Option<&'t T> {
         self.root.as_ref().map(|root| {
             let mut root = root.borrow(token);
@@ -196,28 +264,1440 @@ impl<'a, T> IndexedRBTree<'a, T> {
         })
     }
 
-    pub fn clear(&mut self, token: &mut GhostToken<'a>) {
-        todo!()
+    /// Grows the rightmost element's `weight` by `delta` in place and
+    /// propagates the change to `size` up to the root, without allocating
+    /// a node or touching tree shape. Returns the rightmost value so the
+    /// caller can keep any length it tracks on that value (e.g. a
+    /// `Piece`'s own `len`) in sync. `None` if the tree is empty.
+    pub fn grow_back<'t>(
+        &'t mut self,
+        delta: usize,
+        _token: &'t mut GhostToken<'a>,
+    ) -> Option<&'t mut T> {
+        let root_ptr = self.root.as_ref()?.as_ptr();
+
+        unsafe {
+            let mut ptr = root_ptr;
+            while let Some(r) = (*ptr).right() {
+                ptr = r.as_ptr();
+            }
+
+            (*ptr).weight += delta;
+            (*ptr).size += delta;
+            adjust_ancestors(ptr, delta as isize, 0);
+
+            // SAFETY: `&mut self` borrows the whole tree exclusively, and
+            // `_token` being `&mut` proves no other access into it is live,
+            // so this raw access cannot alias (mirrors `CursorMut::value_mut`).
+            Some(&mut (*ptr).value)
+        }
     }
 
-    fn rotate_left(
-        &mut self,
+    /// Finds the value whose span covers `index`, returning the value
+    /// together with the offset of `index` within that value's span.
+    ///
+    /// This is the standard order-statistic descent on the `size` aggregate:
+    /// at each node, the left subtree covers `[0, ls)`, this node's own span
+    /// covers `[ls, ls + weight)`, and the right subtree covers the rest.
+    pub fn get<'t>(&'t self, mut index: usize, token: &'t GhostToken<'a>) -> Option<(&'t T, usize)> {
+        let mut node = self.root.as_ref()?.borrow(token);
+
+        loop {
+            let left_size = node.left().map(|l| l.borrow(token).size).unwrap_or(0);
+
+            if index < left_size {
+                node = node.left().unwrap().borrow(token);
+                continue;
+            }
+
+            index -= left_size;
+
+            if index < node.weight {
+                return Some((&node.value, index));
+            }
+
+            index -= node.weight;
+            node = node.right()?.borrow(token);
+        }
+    }
+
+    /// Inverse of [`IndexedRBTree::get`]: given a node reached from the root,
+    /// sums up the sizes to its left while walking up to the root, yielding
+    /// the global offset of the start of that node's span.
+    pub(crate) fn offset_of(
         node: &StaticRc<GhostCell<'a, Node<'a, T>>, 1, 3>,
+        token: &GhostToken<'a>,
+    ) -> usize {
+        let mut offset = node
+            .borrow(token)
+            .left()
+            .map(|l| l.borrow(token).size)
+            .unwrap_or(0);
+        let mut current = node;
+
+        while let Some(parent) = current.borrow(token).parent() {
+            let parent_ref = parent.borrow(token);
+
+            let is_right_child = parent_ref
+                .right()
+                .map(|r| std::ptr::eq(r.as_ptr(), current.as_ptr()))
+                .unwrap_or(false);
+
+            if is_right_child {
+                offset += parent_ref
+                    .left()
+                    .map(|l| l.borrow(token).size)
+                    .unwrap_or(0)
+                    + parent_ref.weight;
+            }
+
+            current = parent;
+        }
+
+        offset
+    }
+
+    /// Returns an iterator yielding shared references to the values in tree
+    /// order.
+    pub fn iter<'t>(&'t self, token: &'t GhostToken<'a>) -> Iter<'a, 't, T> {
+        Iter {
+            current: self.root.as_ref().map(|root| leftmost(root, token)),
+            token,
+        }
+    }
+
+    /// Returns an iterator yielding exclusive references to the values in
+    /// tree order.
+    pub fn iter_mut<'t>(&'t self, token: &'t mut GhostToken<'a>) -> IterMut<'a, 't, T> {
+        let token: &'t GhostToken<'a> = token;
+        IterMut {
+            current: self.root.as_ref().map(|root| leftmost(root, token)),
+            token,
+        }
+    }
+
+    /// Returns a cursor seeked to `index` via the order-statistic descent, or
+    /// a past-the-end cursor if `index` is out of bounds.
+    pub fn cursor<'t>(&'t self, index: usize, token: &'t GhostToken<'a>) -> Cursor<'a, 't, T> {
+        Cursor {
+            node: self.root.as_ref().and_then(|root| {
+                let total = root.borrow(token).size;
+                (index < total).then(|| descend_to_index(root, index, token))
+            }),
+            token,
+        }
+    }
+
+    /// Same as [`IndexedRBTree::cursor`] but allows mutating the value at
+    /// the cursor's position.
+    pub fn cursor_mut<'t>(
+        &'t self,
+        index: usize,
+        token: &'t mut GhostToken<'a>,
+    ) -> CursorMut<'a, 't, T> {
+        let token: &'t GhostToken<'a> = token;
+        CursorMut {
+            node: self.root.as_ref().and_then(|root| {
+                let total = root.borrow(token).size;
+                (index < total).then(|| descend_to_index(root, index, token))
+            }),
+            token,
+        }
+    }
+
+    /// Removes every element, freeing all nodes.
+    ///
+    /// Unlike `insert`/`remove`, the teardown in [`Node::drop_recursive`]
+    /// bypasses `GhostCell` entirely via raw pointers, so `token` is only
+    /// taken to match this type's other mutating methods.
+    pub fn clear(&mut self, _token: &mut GhostToken<'a>) {
+        if let Some(root) = self.root.take() {
+            Node::drop_recursive(root);
+        }
+        self.len = 0;
+    }
+
+    /// Rotates `node` (X) left: X's right child (Y) takes X's place, Y's
+    /// former left subtree (B) becomes X's new right subtree, and X becomes
+    /// Y's new left child. `node` must currently have a right child.
+    ///
+    /// `StaticRc` cannot be cloned, so ownership is rewired in place via
+    /// `Node::toggle_left`/`Node::toggle_right` (the same swap trick used
+    /// for every other re-parenting in this module) rather than by moving
+    /// fresh handles around. `size` is refreshed on the two nodes whose
+    /// children changed; a rotation never changes the total weight under
+    /// `node`'s original position, so ancestors above it need no update.
+    fn rotate_left(&mut self, x_ptr: *mut Node<'a, T>, token: &mut GhostToken<'a>) {
+        // SAFETY: every pointer below is derived from `self.root` or one of
+        // its live descendants and is re-read after each `toggle_*` rather
+        // than cached across it, so it always reflects the tree's current
+        // shape. `token` is threaded through only as the proof, required by
+        // `Node::toggle_left`/`Node::toggle_right`, that no other mutation
+        // of the tree is concurrently in flight.
+        unsafe {
+            let y_ptr = (*x_ptr)
+                .right()
+                .expect("rotate_left requires a right child")
+                .as_ptr();
+            let b_ptr = (*y_ptr).left().map(|b| b.as_ptr());
+            let parent_info = (*x_ptr).parent().map(|p| {
+                let p_ptr = p.as_ptr();
+                let was_left = (*p_ptr)
+                    .left()
+                    .map(|l| std::ptr::eq(l.as_ptr(), x_ptr))
+                    .unwrap_or(false);
+                (p_ptr, was_left)
+            });
+            let x_handle = match parent_info {
+                Some((p_ptr, was_left)) if was_left => (*p_ptr).left().unwrap(),
+                Some((p_ptr, _)) => (*p_ptr).right().unwrap(),
+                None => self.root.as_ref().unwrap(),
+            };
+
+            // Detach X from Y: x.right and y.parent both become idle.
+            Node::toggle_right(x_handle, (*x_ptr).right().unwrap(), token);
+
+            if let Some(b_ptr) = b_ptr {
+                // Detach Y from B, then hang B off X's now-idle right slot.
+                // That slot is idle precisely because of the toggle above,
+                // so it must be read as a raw field, not through the
+                // flag-gated accessor.
+                Node::toggle_left((*y_ptr).parent().unwrap(), (*y_ptr).left().unwrap(), token);
+                Node::toggle_right(
+                    (*x_ptr).right.as_ref().unwrap(),
+                    (*b_ptr).parent().unwrap(),
+                    token,
+                );
+            }
+
+            match parent_info {
+                Some((p_ptr, was_left)) => {
+                    // Detach X from its old parent G so x.parent becomes
+                    // idle, matching y.left's idle state, then swap them in.
+                    let g_handle = (*x_ptr).parent().unwrap();
+                    let x_handle = if was_left {
+                        (*p_ptr).left().unwrap()
+                    } else {
+                        (*p_ptr).right().unwrap()
+                    };
+
+                    if was_left {
+                        Node::toggle_left(g_handle, x_handle, token);
+                    } else {
+                        Node::toggle_right(g_handle, x_handle, token);
+                    }
+
+                    Node::toggle_left((*y_ptr).left.as_ref().unwrap(), (*x_ptr).parent().unwrap(), token);
+
+                    // P's old child slot is idle now that X has been
+                    // detached from it, so the flag-gated accessor would
+                    // wrongly report `None`; read the raw field instead.
+                    let g_slot = if was_left {
+                        (*p_ptr).left.as_ref().unwrap()
+                    } else {
+                        (*p_ptr).right.as_ref().unwrap()
+                    };
+                    let y_handle = (*y_ptr).parent().unwrap();
+
+                    if was_left {
+                        Node::toggle_left(g_slot, y_handle, token);
+                    } else {
+                        Node::toggle_right(g_slot, y_handle, token);
+                    }
+                }
+                None => {
+                    // X was the root: stash its root share as an idle
+                    // self-reference so the same toggle machinery applies,
+                    // then promote Y's freed idle share to the root.
+                    let x_root_share = self.root.take().unwrap();
+                    (*x_ptr).parent = Some(x_root_share);
+
+                    Node::toggle_left((*y_ptr).left.as_ref().unwrap(), (*x_ptr).parent().unwrap(), token);
+
+                    let y_root_share = (*y_ptr).parent.take().unwrap();
+                    self.root = Some(y_root_share);
+                }
+            }
+
+            recompute_size(x_ptr);
+            recompute_size(y_ptr);
+        }
+    }
+
+    /// Mirror image of [`IndexedRBTree::rotate_left`].
+    fn rotate_right(&mut self, x_ptr: *mut Node<'a, T>, token: &mut GhostToken<'a>) {
+        // SAFETY: see `rotate_left`; this is its exact mirror image with
+        // left/right swapped throughout.
+        unsafe {
+            let y_ptr = (*x_ptr)
+                .left()
+                .expect("rotate_right requires a left child")
+                .as_ptr();
+            let b_ptr = (*y_ptr).right().map(|b| b.as_ptr());
+            let parent_info = (*x_ptr).parent().map(|p| {
+                let p_ptr = p.as_ptr();
+                let was_left = (*p_ptr)
+                    .left()
+                    .map(|l| std::ptr::eq(l.as_ptr(), x_ptr))
+                    .unwrap_or(false);
+                (p_ptr, was_left)
+            });
+            let x_handle = match parent_info {
+                Some((p_ptr, was_left)) if was_left => (*p_ptr).left().unwrap(),
+                Some((p_ptr, _)) => (*p_ptr).right().unwrap(),
+                None => self.root.as_ref().unwrap(),
+            };
+
+            Node::toggle_left(x_handle, (*x_ptr).left().unwrap(), token);
+
+            if let Some(b_ptr) = b_ptr {
+                // Same idle-slot subtlety as in `rotate_left`.
+                Node::toggle_right((*y_ptr).parent().unwrap(), (*y_ptr).right().unwrap(), token);
+                Node::toggle_left(
+                    (*x_ptr).left.as_ref().unwrap(),
+                    (*b_ptr).parent().unwrap(),
+                    token,
+                );
+            }
+
+            match parent_info {
+                Some((p_ptr, was_left)) => {
+                    let g_handle = (*x_ptr).parent().unwrap();
+                    let x_handle = if was_left {
+                        (*p_ptr).left().unwrap()
+                    } else {
+                        (*p_ptr).right().unwrap()
+                    };
+
+                    if was_left {
+                        Node::toggle_left(g_handle, x_handle, token);
+                    } else {
+                        Node::toggle_right(g_handle, x_handle, token);
+                    }
+
+                    Node::toggle_right((*y_ptr).right.as_ref().unwrap(), (*x_ptr).parent().unwrap(), token);
+
+                    // Same idle-slot subtlety as in `rotate_left`: P's old
+                    // child slot must be read as a raw field, not through
+                    // the flag-gated accessor.
+                    let g_slot = if was_left {
+                        (*p_ptr).left.as_ref().unwrap()
+                    } else {
+                        (*p_ptr).right.as_ref().unwrap()
+                    };
+                    let y_handle = (*y_ptr).parent().unwrap();
+
+                    if was_left {
+                        Node::toggle_left(g_slot, y_handle, token);
+                    } else {
+                        Node::toggle_right(g_slot, y_handle, token);
+                    }
+                }
+                None => {
+                    let x_root_share = self.root.take().unwrap();
+                    (*x_ptr).parent = Some(x_root_share);
+
+                    Node::toggle_right((*y_ptr).right.as_ref().unwrap(), (*x_ptr).parent().unwrap(), token);
+
+                    let y_root_share = (*y_ptr).parent.take().unwrap();
+                    self.root = Some(y_root_share);
+                }
+            }
+
+            recompute_size(x_ptr);
+            recompute_size(y_ptr);
+        }
+    }
+
+    /// Inserts `value` (spanning `weight` units) so that it starts at
+    /// offset `index` in the in-order sequence.
+    ///
+    /// Descends by the same order-statistic rule as [`IndexedRBTree::get`]
+    /// to find the leaf position for `index`, splices in a new `Node`
+    /// there, then restores the red-black invariants with
+    /// [`IndexedRBTree::insert_fixup`]. If `index` lands strictly inside an
+    /// existing node's span rather than on a boundary between two spans,
+    /// the new node is attached immediately after that node; splitting an
+    /// existing span is the caller's responsibility.
+    pub fn insert(&mut self, value: T, weight: usize, mut index: usize, token: &mut GhostToken<'a>) {
+        let new_node = Node::new(value, weight, token);
+
+        let root = match self.root.take() {
+            None => {
+                // SAFETY: `new_node` was just created and is not yet shared.
+                unsafe {
+                    (*new_node.as_ptr()).set_black();
+                }
+                self.root = Some(new_node);
+                self.len += 1;
+                return;
+            }
+            Some(root) => root,
+        };
+        self.root = Some(root);
+
+        // SAFETY: every pointer below is derived from `self.root` or one of
+        // its live descendants, re-read after each structural change.
+        unsafe {
+            let new_ptr = new_node.as_ptr();
+            let mut cur_ptr = self.root.as_ref().unwrap().as_ptr();
+
+            loop {
+                let left = (*cur_ptr).left();
+                let left_size = left.map(|l| (*l.as_ptr()).size).unwrap_or(0);
+
+                if index <= left_size {
+                    match left {
+                        Some(left) => {
+                            cur_ptr = left.as_ptr();
+                            continue;
+                        }
+                        None => {
+                            attach_left(cur_ptr, new_node);
+                            break;
+                        }
+                    }
+                }
+
+                let rel = index - left_size;
+                index = rel.saturating_sub((*cur_ptr).weight);
+
+                match (*cur_ptr).right() {
+                    Some(right) => cur_ptr = right.as_ptr(),
+                    None => {
+                        attach_right(cur_ptr, new_node);
+                        break;
+                    }
+                }
+            }
+
+            self.len += 1;
+            adjust_ancestors(new_ptr, weight as isize, 1);
+            self.insert_fixup(new_ptr, token);
+        }
+    }
+
+    /// Restores the red-black invariants after [`IndexedRBTree::insert`]
+    /// attaches `z` as a new red leaf, by recoloring and rotating up from
+    /// `z` exactly as in the classic (CLRS) red-black insert fixup.
+    fn insert_fixup(&mut self, mut z: *mut Node<'a, T>, token: &mut GhostToken<'a>) {
+        // SAFETY: `z` and every pointer derived from it below are live
+        // descendants of `self.root`, re-read after each rotation.
+        unsafe {
+            while let Some(p) = (*z).parent() {
+                let parent = p.as_ptr();
+
+                if (*parent).is_black() {
+                    break;
+                }
+
+                // `parent` is red, so it cannot be the root, so it has a
+                // grandparent.
+                let grandparent = (*parent).parent().unwrap().as_ptr();
+                let parent_is_left = (*grandparent)
+                    .left()
+                    .map(|l| std::ptr::eq(l.as_ptr(), parent))
+                    .unwrap_or(false);
+
+                if parent_is_left {
+                    let uncle = (*grandparent).right();
+                    if uncle.map(|u| !(*u.as_ptr()).is_black()).unwrap_or(false) {
+                        (*parent).set_black();
+                        (*uncle.unwrap().as_ptr()).set_black();
+                        (*grandparent).set_red();
+                        z = grandparent;
+                        continue;
+                    }
+
+                    if (*parent)
+                        .right()
+                        .map(|r| std::ptr::eq(r.as_ptr(), z))
+                        .unwrap_or(false)
+                    {
+                        z = parent;
+                        self.rotate_left(z, token);
+                    }
+
+                    let parent = (*z).parent().unwrap().as_ptr();
+                    let grandparent = (*parent).parent().unwrap().as_ptr();
+                    (*parent).set_black();
+                    (*grandparent).set_red();
+                    self.rotate_right(grandparent, token);
+                } else {
+                    let uncle = (*grandparent).left();
+                    if uncle.map(|u| !(*u.as_ptr()).is_black()).unwrap_or(false) {
+                        (*parent).set_black();
+                        (*uncle.unwrap().as_ptr()).set_black();
+                        (*grandparent).set_red();
+                        z = grandparent;
+                        continue;
+                    }
+
+                    if (*parent)
+                        .left()
+                        .map(|l| std::ptr::eq(l.as_ptr(), z))
+                        .unwrap_or(false)
+                    {
+                        z = parent;
+                        self.rotate_right(z, token);
+                    }
+
+                    let parent = (*z).parent().unwrap().as_ptr();
+                    let grandparent = (*parent).parent().unwrap().as_ptr();
+                    (*parent).set_black();
+                    (*grandparent).set_red();
+                    self.rotate_left(grandparent, token);
+                }
+            }
+
+            (*self.root.as_ref().unwrap().as_ptr()).set_black();
+        }
+    }
+
+    /// Removes the value whose span contains `index`, maintaining the
+    /// red-black invariants and decrementing `size` up the spine exactly
+    /// as [`IndexedRBTree::insert`] increments it.
+    ///
+    /// If that node has two children, it is not itself destroyed: its
+    /// in-order successor's value and weight are swapped into it first
+    /// (the usual technique for avoiding a three-way ownership relink),
+    /// and the successor's now-redundant node is what is actually excised.
+    pub fn remove(&mut self, index: usize, token: &mut GhostToken<'a>) -> Option<T> {
+        let total = self.root.as_ref()?.borrow(&*token).size;
+        if index >= total {
+            return None;
+        }
+
+        let mut remaining = index;
+
+        // SAFETY: every pointer below is derived from `self.root` or one of
+        // its live descendants, re-read after each structural change.
+        unsafe {
+            let mut z_ptr = self.root.as_ref().unwrap().as_ptr();
+
+            loop {
+                let left_size = (*z_ptr).left().map(|l| (*l.as_ptr()).size).unwrap_or(0);
+
+                if remaining < left_size {
+                    z_ptr = (*z_ptr).left().unwrap().as_ptr();
+                    continue;
+                }
+
+                remaining -= left_size;
+
+                if remaining < (*z_ptr).weight {
+                    break;
+                }
+
+                remaining -= (*z_ptr).weight;
+                z_ptr = (*z_ptr).right().unwrap().as_ptr();
+            }
+
+            if (*z_ptr).left().is_some() && (*z_ptr).right().is_some() {
+                let mut y_ptr = (*z_ptr).right().unwrap().as_ptr();
+                while let Some(left) = (*y_ptr).left() {
+                    y_ptr = left.as_ptr();
+                }
+
+                mem::swap(&mut (*z_ptr).value, &mut (*y_ptr).value);
+                mem::swap(&mut (*z_ptr).weight, &mut (*y_ptr).weight);
+
+                // The swap moved weight between `z` and `y`, so refresh
+                // `size` on `y` and every node on the path up to `z`.
+                let mut p = y_ptr;
+                recompute_size(p);
+                while !std::ptr::eq(p, z_ptr) {
+                    p = (*p).parent().unwrap().as_ptr();
+                    recompute_size(p);
+                }
+
+                z_ptr = y_ptr;
+            }
+
+            let removed_weight = (*z_ptr).weight;
+            let was_black = (*z_ptr).is_black();
+
+            adjust_ancestors(z_ptr, -(removed_weight as isize), -1);
+
+            let (isolated, x_ptr, parent_ptr, x_is_left) = excise(self, z_ptr, token);
+            self.len -= 1;
+
+            if was_black {
+                self.remove_fixup(x_ptr, parent_ptr, x_is_left, token);
+            }
+
+            let node = GhostCell::into_inner(StaticRc::into_inner(isolated));
+            Some(node.value)
+        }
+    }
+
+    /// Restores the red-black invariants after [`IndexedRBTree::remove`]
+    /// excises a black node, by walking the "double black" defect up from
+    /// the position it left behind (`x_ptr`, possibly absent since there is
+    /// no sentinel node here, in which case `parent_ptr`/`x_is_left` name
+    /// that position instead) exactly as in the classic (CLRS) red-black
+    /// delete fixup.
+    fn remove_fixup(
+        &mut self,
+        mut x_ptr: Option<*mut Node<'a, T>>,
+        mut parent_ptr: Option<*mut Node<'a, T>>,
+        mut x_is_left: bool,
         token: &mut GhostToken<'a>,
     ) {
-        todo!()
+        // SAFETY: every pointer below is derived from `self.root` or one of
+        // its live descendants, re-read after each rotation.
+        unsafe {
+            while let Some(p_ptr) = parent_ptr {
+                if !x_ptr.map(|x| (*x).is_black()).unwrap_or(true) {
+                    break;
+                }
+
+                if x_is_left {
+                    let mut w_ptr = (*p_ptr).right().unwrap().as_ptr();
+
+                    if !(*w_ptr).is_black() {
+                        (*w_ptr).set_black();
+                        (*p_ptr).set_red();
+                        self.rotate_left(p_ptr, token);
+                        w_ptr = (*p_ptr).right().unwrap().as_ptr();
+                    }
+
+                    let left_black = (*w_ptr)
+                        .left()
+                        .map(|n| (*n.as_ptr()).is_black())
+                        .unwrap_or(true);
+                    let right_black = (*w_ptr)
+                        .right()
+                        .map(|n| (*n.as_ptr()).is_black())
+                        .unwrap_or(true);
+
+                    if left_black && right_black {
+                        (*w_ptr).set_red();
+                        x_ptr = Some(p_ptr);
+                        parent_ptr = (*p_ptr).parent().map(|p| p.as_ptr());
+                        x_is_left = parent_ptr
+                            .map(|gp| {
+                                (*gp)
+                                    .left()
+                                    .map(|l| std::ptr::eq(l.as_ptr(), p_ptr))
+                                    .unwrap_or(false)
+                            })
+                            .unwrap_or(false);
+                    } else {
+                        if right_black {
+                            if let Some(l) = (*w_ptr).left() {
+                                (*l.as_ptr()).set_black();
+                            }
+                            (*w_ptr).set_red();
+                            self.rotate_right(w_ptr, token);
+                            w_ptr = (*p_ptr).right().unwrap().as_ptr();
+                        }
+
+                        if (*p_ptr).is_black() {
+                            (*w_ptr).set_black();
+                        } else {
+                            (*w_ptr).set_red();
+                        }
+                        (*p_ptr).set_black();
+                        if let Some(r) = (*w_ptr).right() {
+                            (*r.as_ptr()).set_black();
+                        }
+                        self.rotate_left(p_ptr, token);
+                        x_ptr = None;
+                        parent_ptr = None;
+                    }
+                } else {
+                    let mut w_ptr = (*p_ptr).left().unwrap().as_ptr();
+
+                    if !(*w_ptr).is_black() {
+                        (*w_ptr).set_black();
+                        (*p_ptr).set_red();
+                        self.rotate_right(p_ptr, token);
+                        w_ptr = (*p_ptr).left().unwrap().as_ptr();
+                    }
+
+                    let left_black = (*w_ptr)
+                        .left()
+                        .map(|n| (*n.as_ptr()).is_black())
+                        .unwrap_or(true);
+                    let right_black = (*w_ptr)
+                        .right()
+                        .map(|n| (*n.as_ptr()).is_black())
+                        .unwrap_or(true);
+
+                    if left_black && right_black {
+                        (*w_ptr).set_red();
+                        x_ptr = Some(p_ptr);
+                        parent_ptr = (*p_ptr).parent().map(|p| p.as_ptr());
+                        x_is_left = parent_ptr
+                            .map(|gp| {
+                                (*gp)
+                                    .left()
+                                    .map(|l| std::ptr::eq(l.as_ptr(), p_ptr))
+                                    .unwrap_or(false)
+                            })
+                            .unwrap_or(false);
+                    } else {
+                        if left_black {
+                            if let Some(r) = (*w_ptr).right() {
+                                (*r.as_ptr()).set_black();
+                            }
+                            (*w_ptr).set_red();
+                            self.rotate_left(w_ptr, token);
+                            w_ptr = (*p_ptr).left().unwrap().as_ptr();
+                        }
+
+                        if (*p_ptr).is_black() {
+                            (*w_ptr).set_black();
+                        } else {
+                            (*w_ptr).set_red();
+                        }
+                        (*p_ptr).set_black();
+                        if let Some(l) = (*w_ptr).left() {
+                            (*l.as_ptr()).set_black();
+                        }
+                        self.rotate_right(p_ptr, token);
+                        x_ptr = None;
+                        parent_ptr = None;
+                    }
+                }
+            }
+
+            if let Some(x) = x_ptr {
+                (*x).set_black();
+            }
+        }
     }
 
-    fn rotate_right(
+    /// Partitions the tree at `index`, mirroring `Vec::split_off`: after
+    /// the call, `self` holds everything before `index` and the returned
+    /// tree holds everything from `index` onward. `index` past the end of
+    /// the tree is clamped, so the returned tree is simply empty.
+    ///
+    /// If `index` lands strictly inside an existing node's span rather
+    /// than on a boundary between two spans, `split_value` is called once
+    /// with that node's value and the offset of `index` within it, and
+    /// must return the two halves (a `Piece`, say, splits into two
+    /// sub-ranges of the same buffer). It is never called when `index`
+    /// already lands on a span boundary.
+    ///
+    /// Implemented as a recursive sequence of [`join`]s while walking down
+    /// the search path to `index`: every node not on that path is, as a
+    /// whole subtree, glued wholesale onto whichever side it falls on, and
+    /// the one node the path does pass through is split in two and each
+    /// half joined onto the matching side.
+    pub fn split_at<F>(
         &mut self,
-        node: &StaticRc<GhostCell<'a, Node<'a, T>>, 1, 3>,
+        index: usize,
+        mut split_value: F,
         token: &mut GhostToken<'a>,
-    ) {
-        todo!()
+    ) -> IndexedRBTree<'a, T>
+    where
+        F: FnMut(T, usize) -> (T, T),
+    {
+        let total = self.root.as_ref().map(|r| r.borrow(token).size).unwrap_or(0);
+        let index = index.min(total);
+        let whole = mem::replace(self, IndexedRBTree::new());
+        let (left, right) = split_recursive(whole, index, &mut split_value, token);
+        *self = left;
+        right
+    }
+
+    /// Appends `other` after every existing element of `self` in
+    /// O(log n).
+    ///
+    /// [`join`] needs a separator value to glue the two trees' spines
+    /// together, so this pops `self`'s own last value off first (see
+    /// [`pop_last`]) and feeds it back in as that separator, rather than
+    /// requiring the caller to supply one.
+    pub fn concat(&mut self, other: IndexedRBTree<'a, T>, token: &mut GhostToken<'a>) {
+        if other.is_empty() {
+            return;
+        }
+        if self.is_empty() {
+            *self = other;
+            return;
+        }
+
+        let whole = mem::replace(self, IndexedRBTree::new());
+        let (left, mid_value, mid_weight) = pop_last(whole, token);
+        *self = join(left, mid_value, mid_weight, other, token);
+    }
+}
+
+impl<'a, T> Drop for IndexedRBTree<'a, T> {
+    fn drop(&mut self) {
+        if let Some(root) = self.root.take() {
+            Node::drop_recursive(root);
+        }
+    }
+}
+
+type NodeRc<'a, T> = StaticRc<GhostCell<'a, Node<'a, T>>, 1, 3>;
+
+/// What [`excise`] hands back to [`IndexedRBTree::remove`]: the excised
+/// node reassembled into a whole `StaticRc` ready for
+/// `GhostCell::into_inner`, the child that was spliced into its former
+/// position (if any), the parent position it was spliced into (`None` if
+/// the excised node was the root), and whether that position is a left
+/// child.
+type ExciseResult<'a, T> = (
+    StaticRc<GhostCell<'a, Node<'a, T>>, 3, 3>,
+    Option<*mut Node<'a, T>>,
+    Option<*mut Node<'a, T>>,
+    bool,
+);
+
+/// Recomputes `size` and `count` at `ptr` from its children's aggregates
+/// and its own `weight`. Used after any rotation, which rearranges
+/// children without changing the total weight or node count of the
+/// subtree rooted at either endpoint.
+unsafe fn recompute_size<'a, T>(ptr: *mut Node<'a, T>) {
+    let left_size = (*ptr).left().map(|l| (*l.as_ptr()).size).unwrap_or(0);
+    let right_size = (*ptr).right().map(|r| (*r.as_ptr()).size).unwrap_or(0);
+    (*ptr).size = left_size + (*ptr).weight + right_size;
+
+    let left_count = (*ptr).left().map(|l| (*l.as_ptr()).count).unwrap_or(0);
+    let right_count = (*ptr).right().map(|r| (*r.as_ptr()).count).unwrap_or(0);
+    (*ptr).count = left_count + 1 + right_count;
+}
+
+/// Applies `size_delta` to `.size` and `count_delta` to `.count` on every
+/// strict ancestor of `ptr`, from its immediate parent up to the root.
+/// `ptr`'s own aggregates are assumed already correct (a freshly inserted
+/// leaf's `size` equals its `weight` and its `count` is 1; a node about to
+/// be removed no longer needs either).
+unsafe fn adjust_ancestors<'a, T>(ptr: *mut Node<'a, T>, size_delta: isize, count_delta: isize) {
+    let mut ptr = ptr;
+    while let Some(parent) = (*ptr).parent() {
+        let parent_ptr = parent.as_ptr();
+        let size = &mut (*parent_ptr).size;
+        *size = (*size as isize + size_delta) as usize;
+        let count = &mut (*parent_ptr).count;
+        *count = (*count as isize + count_delta) as usize;
+        ptr = parent_ptr;
+    }
+}
+
+/// Attaches a node whose `parent` field is `None` (a brand-new node from
+/// [`Node::new`], a tree's root, or a subtree returned by [`detach_left`]/
+/// [`detach_right`]) as `parent`'s left child, taking over the idle
+/// self-share `parent` was holding in its own `left` field.
+unsafe fn attach_left<'a, T>(parent: *mut Node<'a, T>, child: NodeRc<'a, T>) {
+    let idle = (*parent).left.take().unwrap();
+    let child_ptr = child.as_ptr();
+    (*parent).left = Some(child);
+    (*parent).flags.insert(NodeFlags::HAS_LEFT);
+    (*child_ptr).parent = Some(idle);
+}
+
+/// Mirror image of [`attach_left`].
+unsafe fn attach_right<'a, T>(parent: *mut Node<'a, T>, child: NodeRc<'a, T>) {
+    let idle = (*parent).right.take().unwrap();
+    let child_ptr = child.as_ptr();
+    (*parent).right = Some(child);
+    (*parent).flags.insert(NodeFlags::HAS_RIGHT);
+    (*child_ptr).parent = Some(idle);
+}
+
+/// Inverse of [`attach_left`]: detaches `parent`'s left child, restoring
+/// the idle self-share `parent` held before the child was attached, and
+/// returns the detached subtree with its own `parent` field vacated
+/// (ready to be handed to [`attach_left`]/[`attach_right`] elsewhere, as
+/// [`join`] does).
+unsafe fn detach_left<'a, T>(parent: *mut Node<'a, T>) -> NodeRc<'a, T> {
+    let child = (*parent).left.take().unwrap();
+    let idle = (*child.as_ptr()).parent.take().unwrap();
+    (*parent).left = Some(idle);
+    (*parent).flags.remove(NodeFlags::HAS_LEFT);
+    child
+}
+
+/// Mirror image of [`detach_left`].
+unsafe fn detach_right<'a, T>(parent: *mut Node<'a, T>) -> NodeRc<'a, T> {
+    let child = (*parent).right.take().unwrap();
+    let idle = (*child.as_ptr()).parent.take().unwrap();
+    (*parent).right = Some(idle);
+    (*parent).flags.remove(NodeFlags::HAS_RIGHT);
+    child
+}
+
+/// Physically detaches `u` (which must have at most one child) from the
+/// tree, splicing that child (if any) into `u`'s former position exactly
+/// as [`IndexedRBTree::rotate_left`]/[`IndexedRBTree::rotate_right`]
+/// splice nodes during a rotation, and returns `u` reassembled into a
+/// single `StaticRc<_, 3, 3>` (ready for `GhostCell::into_inner`) along
+/// with what [`IndexedRBTree::remove_fixup`] needs: the child that
+/// replaced `u` (if any), the parent position it was spliced into (`None`
+/// if `u` was the root), and whether that position is a left child.
+unsafe fn excise<'a, T>(
+    tree: &mut IndexedRBTree<'a, T>,
+    u_ptr: *mut Node<'a, T>,
+    token: &mut GhostToken<'a>,
+) -> ExciseResult<'a, T> {
+    let child_is_left = (*u_ptr).left().is_some();
+    let child_ptr = if child_is_left {
+        (*u_ptr).left().map(|l| l.as_ptr())
+    } else {
+        (*u_ptr).right().map(|r| r.as_ptr())
+    };
+
+    if child_ptr.is_some() {
+        let u_handle = match (*u_ptr).parent() {
+            Some(p) => {
+                let p_ptr = p.as_ptr();
+                if (*p_ptr)
+                    .left()
+                    .map(|l| std::ptr::eq(l.as_ptr(), u_ptr))
+                    .unwrap_or(false)
+                {
+                    (*p_ptr).left().unwrap()
+                } else {
+                    (*p_ptr).right().unwrap()
+                }
+            }
+            None => tree.root.as_ref().unwrap(),
+        };
+
+        if child_is_left {
+            Node::toggle_left(u_handle, (*u_ptr).left().unwrap(), token);
+        } else {
+            Node::toggle_right(u_handle, (*u_ptr).right().unwrap(), token);
+        }
+    }
+
+    let parent_ptr = (*u_ptr).parent().map(|p| p.as_ptr());
+
+    match parent_ptr {
+        Some(p_ptr) => {
+            let u_is_left = (*p_ptr)
+                .left()
+                .map(|l| std::ptr::eq(l.as_ptr(), u_ptr))
+                .unwrap_or(false);
+
+            if u_is_left {
+                Node::toggle_left((*u_ptr).parent().unwrap(), (*p_ptr).left().unwrap(), token);
+            } else {
+                Node::toggle_right((*u_ptr).parent().unwrap(), (*p_ptr).right().unwrap(), token);
+            }
+
+            if let Some(child_ptr) = child_ptr {
+                // P's slot for `u` is idle now that `u` was just detached
+                // above, so it must be read as a raw field rather than
+                // through the flag-gated accessor.
+                if u_is_left {
+                    Node::toggle_left(
+                        (*p_ptr).left.as_ref().unwrap(),
+                        (*child_ptr).parent().unwrap(),
+                        token,
+                    );
+                } else {
+                    Node::toggle_right(
+                        (*p_ptr).right.as_ref().unwrap(),
+                        (*child_ptr).parent().unwrap(),
+                        token,
+                    );
+                }
+            }
+
+            ((*u_ptr).join_rc(), child_ptr, Some(p_ptr), u_is_left)
+        }
+        None => {
+            let u_root_share = tree.root.take().unwrap();
+            (*u_ptr).parent = Some(u_root_share);
+
+            if let Some(child_ptr) = child_ptr {
+                let child_root_share = (*child_ptr).parent.take().unwrap();
+                tree.root = Some(child_root_share);
+            }
+
+            ((*u_ptr).join_rc(), child_ptr, None, false)
+        }
+    }
+}
+
+/// The black-height of the subtree rooted at `ptr` (0 for an empty
+/// subtree): the number of black nodes on the path from `ptr` down to any
+/// leaf, `ptr` included. Every root-to-leaf path in a valid red-black tree
+/// has the same count, so it is enough to follow just the left spine.
+unsafe fn black_height<'a, T>(ptr: Option<*mut Node<'a, T>>) -> usize {
+    match ptr {
+        None => 0,
+        Some(p) => {
+            let child_bh = black_height((*p).left().map(|l| l.as_ptr()));
+            child_bh + if (*p).is_black() { 1 } else { 0 }
+        }
+    }
+}
+
+/// Descends `root_ptr`'s right spine to the highest black node whose subtree
+/// has black-height `target_bh`, returning that node's parent (`None` if
+/// `root_ptr` itself is the match, i.e. the two trees being joined already
+/// have equal black-height). Used by [`join`] when the left-hand tree is
+/// at least as tall (in black-height) as the right-hand one.
+unsafe fn find_join_point_right<'a, T>(
+    root_ptr: *mut Node<'a, T>,
+    target_bh: usize,
+) -> Option<*mut Node<'a, T>> {
+    let mut remaining = black_height(Some(root_ptr));
+    let mut parent = None;
+    let mut c = root_ptr;
+
+    while remaining > target_bh || (remaining == target_bh && !(*c).is_black()) {
+        if (*c).is_black() {
+            remaining -= 1;
+        }
+        parent = Some(c);
+        c = (*c)
+            .right()
+            .expect("join: right spine ran out before matching black-height")
+            .as_ptr();
+    }
+
+    parent
+}
+
+/// Mirror image of [`find_join_point_right`], descending `root_ptr`'s left
+/// spine instead. Used when the right-hand tree is the taller one.
+unsafe fn find_join_point_left<'a, T>(
+    root_ptr: *mut Node<'a, T>,
+    target_bh: usize,
+) -> Option<*mut Node<'a, T>> {
+    let mut remaining = black_height(Some(root_ptr));
+    let mut parent = None;
+    let mut c = root_ptr;
+
+    while remaining > target_bh || (remaining == target_bh && !(*c).is_black()) {
+        if (*c).is_black() {
+            remaining -= 1;
+        }
+        parent = Some(c);
+        c = (*c)
+            .left()
+            .expect("join: left spine ran out before matching black-height")
+            .as_ptr();
+    }
+
+    parent
+}
+
+/// Glues `left`, a new separator (`mid_value`/`mid_weight`), and `right`
+/// into one tree in O(log(`left.len()` + `right.len()`)).
+///
+/// Compares black-heights to find, on the taller side's outer spine, the
+/// subtree whose black-height matches the shorter side, splices a new red
+/// node holding the separator in its place (with that subtree and the
+/// shorter tree as its two children), and restores the red-black
+/// invariants with the ordinary insert fixup — the same shape of fix-up
+/// as inserting a brand-new red leaf, just deeper in the tree.
+fn join<'a, T>(
+    mut left: IndexedRBTree<'a, T>,
+    mid_value: T,
+    mid_weight: usize,
+    mut right: IndexedRBTree<'a, T>,
+    token: &mut GhostToken<'a>,
+) -> IndexedRBTree<'a, T> {
+    if left.is_empty() {
+        right.insert(mid_value, mid_weight, 0, token);
+        return right;
+    }
+    if right.is_empty() {
+        let at = left.root.as_ref().unwrap().borrow(token).size;
+        left.insert(mid_value, mid_weight, at, token);
+        return left;
+    }
+
+    let left_len = left.len();
+    let right_len = right.len();
+    let mid = Node::new(mid_value, mid_weight, token);
+    let mid_ptr = mid.as_ptr();
+
+    // SAFETY: every pointer below is derived from `left.root`/`right.root`
+    // or one of their live descendants, both uniquely owned here.
+    unsafe {
+        let left_bh = black_height(left.root.as_ref().map(|r| r.as_ptr()));
+        let right_bh = black_height(right.root.as_ref().map(|r| r.as_ptr()));
+        let left_size = left.root.as_ref().unwrap().borrow(token).size;
+        let right_size = right.root.as_ref().unwrap().borrow(token).size;
+
+        if left_bh >= right_bh {
+            let root_ptr = left.root.as_ref().unwrap().as_ptr();
+            let parent_ptr = find_join_point_right(root_ptr, right_bh);
+            let spine_subtree = match parent_ptr {
+                Some(p) => detach_right(p),
+                None => left.root.take().unwrap(),
+            };
+
+            attach_left(mid_ptr, spine_subtree);
+            attach_right(mid_ptr, right.root.take().unwrap());
+            recompute_size(mid_ptr);
+
+            match parent_ptr {
+                Some(p) => attach_right(p, mid),
+                None => left.root = Some(mid),
+            }
+            adjust_ancestors(mid_ptr, (mid_weight + right_size) as isize, (1 + right_len) as isize);
+
+            left.len = left_len + 1 + right_len;
+            left.insert_fixup(mid_ptr, token);
+            left
+        } else {
+            let root_ptr = right.root.as_ref().unwrap().as_ptr();
+            let parent_ptr = find_join_point_left(root_ptr, left_bh);
+            let spine_subtree = match parent_ptr {
+                Some(p) => detach_left(p),
+                None => right.root.take().unwrap(),
+            };
+
+            attach_right(mid_ptr, spine_subtree);
+            attach_left(mid_ptr, left.root.take().unwrap());
+            recompute_size(mid_ptr);
+
+            match parent_ptr {
+                Some(p) => attach_left(p, mid),
+                None => right.root = Some(mid),
+            }
+            adjust_ancestors(mid_ptr, (mid_weight + left_size) as isize, (1 + left_len) as isize);
+
+            right.len = left_len + 1 + right_len;
+            right.insert_fixup(mid_ptr, token);
+            right
+        }
+    }
+}
+
+/// Removes and returns the last (rightmost) value in `tree` together with
+/// its weight, leaving everything before it in `tree`. Used by
+/// [`IndexedRBTree::concat`] to obtain a separator for [`join`] without
+/// requiring the caller to supply one.
+fn pop_last<'a, T>(
+    mut tree: IndexedRBTree<'a, T>,
+    token: &mut GhostToken<'a>,
+) -> (IndexedRBTree<'a, T>, T, usize) {
+    // SAFETY: every pointer below is derived from `tree.root` or one of
+    // its live descendants, re-read after each structural change. `tree`
+    // is non-empty: its only caller, `concat`, checks `is_empty` first.
+    unsafe {
+        let mut z_ptr = tree.root.as_ref().unwrap().as_ptr();
+        while let Some(r) = (*z_ptr).right() {
+            z_ptr = r.as_ptr();
+        }
+
+        let weight = (*z_ptr).weight;
+        let was_black = (*z_ptr).is_black();
+
+        adjust_ancestors(z_ptr, -(weight as isize), -1);
+
+        let (isolated, x_ptr, parent_ptr, x_is_left) = excise(&mut tree, z_ptr, token);
+        tree.len -= 1;
+
+        if was_black {
+            tree.remove_fixup(x_ptr, parent_ptr, x_is_left, token);
+        }
+
+        let value = GhostCell::into_inner(StaticRc::into_inner(isolated)).value;
+        (tree, value, weight)
+    }
+}
+
+/// Detaches `ptr`'s left child as its own standalone tree, or an empty
+/// tree if `ptr` has none. Used by [`split_recursive`] to pull a node's
+/// children out from under it before deciding which side of the split
+/// each belongs on.
+///
+/// The detached child is forced black: a non-root subtree is free to have
+/// a red root (only the overall tree's root must be black), but once it
+/// stands alone as its own [`IndexedRBTree`] that invariant applies to it
+/// too. Recoloring a root black can only ever raise its black-height, so
+/// this never violates the equal-black-height invariant on its paths.
+unsafe fn take_left_subtree<'a, T>(ptr: *mut Node<'a, T>) -> IndexedRBTree<'a, T> {
+    match (*ptr).left() {
+        Some(l) => {
+            let l_ptr = l.as_ptr();
+            let count = (*l_ptr).count;
+            let detached = detach_left(ptr);
+            (*l_ptr).set_black();
+            IndexedRBTree {
+                len: count,
+                root: Some(detached),
+            }
+        }
+        None => IndexedRBTree::new(),
+    }
+}
+
+/// Mirror image of [`take_left_subtree`].
+unsafe fn take_right_subtree<'a, T>(ptr: *mut Node<'a, T>) -> IndexedRBTree<'a, T> {
+    match (*ptr).right() {
+        Some(r) => {
+            let r_ptr = r.as_ptr();
+            let count = (*r_ptr).count;
+            let detached = detach_right(ptr);
+            (*r_ptr).set_black();
+            IndexedRBTree {
+                len: count,
+                root: Some(detached),
+            }
+        }
+        None => IndexedRBTree::new(),
+    }
+}
+
+/// The recursive half of [`IndexedRBTree::split_at`]: consumes `tree` and
+/// returns the two trees either side of `index`, joining each node not on
+/// the search path onto the correct side wholesale and splitting the one
+/// node the path does pass through (via `split_value`) into the two
+/// halves that straddle `index`.
+fn split_recursive<'a, T, F>(
+    mut tree: IndexedRBTree<'a, T>,
+    index: usize,
+    split_value: &mut F,
+    token: &mut GhostToken<'a>,
+) -> (IndexedRBTree<'a, T>, IndexedRBTree<'a, T>)
+where
+    F: FnMut(T, usize) -> (T, T),
+{
+    let root = match tree.root.take() {
+        None => return (IndexedRBTree::new(), IndexedRBTree::new()),
+        Some(root) => root,
+    };
+
+    // SAFETY: `root` was just detached from `tree`, so every pointer
+    // derived from it below is uniquely owned by this call.
+    unsafe {
+        let root_ptr = root.as_ptr();
+        let left_size = (*root_ptr).left().map(|l| (*l.as_ptr()).size).unwrap_or(0);
+        let weight = (*root_ptr).weight;
+
+        let left_subtree = take_left_subtree(root_ptr);
+        let right_subtree = take_right_subtree(root_ptr);
+
+        // Reassemble `root`'s three thirds (its own share, now stashed
+        // back into its vacant `parent` field, plus the idle self-shares
+        // `take_left_subtree`/`take_right_subtree` left behind) so its
+        // value can be moved out, exactly as `excise` does for the root.
+        (*root_ptr).parent = Some(root);
+        let isolated = (*root_ptr).join_rc();
+        let value = GhostCell::into_inner(StaticRc::into_inner(isolated)).value;
+
+        if index <= left_size {
+            let (ll, lr) = split_recursive(left_subtree, index, split_value, token);
+            let right = join(lr, value, weight, right_subtree, token);
+            (ll, right)
+        } else if index < left_size + weight {
+            let local = index - left_size;
+            let (v_left, v_right) = split_value(value, local);
+            let left = join(left_subtree, v_left, local, IndexedRBTree::new(), token);
+            let right = join(IndexedRBTree::new(), v_right, weight - local, right_subtree, token);
+            (left, right)
+        } else {
+            let remaining = index - left_size - weight;
+            let (rl, rr) = split_recursive(right_subtree, remaining, split_value, token);
+            let left = join(left_subtree, value, weight, rl, token);
+            (left, rr)
+        }
+    }
+}
+
+/// Descends from `node` following `left()` links until there is none left.
+fn leftmost<'a, 't, T>(node: &'t NodeRc<'a, T>, token: &'t GhostToken<'a>) -> &'t NodeRc<'a, T> {
+    let mut node = node;
+
+    while let Some(left) = node.borrow(token).left() {
+        node = left;
     }
 
-    pub fn insert(&mut self, value: T, index: usize, token: &mut GhostToken<'a>) {
-        todo!()
+    node
+}
+
+/// Descends from `node` following `right()` links until there is none left.
+fn rightmost<'a, 't, T>(node: &'t NodeRc<'a, T>, token: &'t GhostToken<'a>) -> &'t NodeRc<'a, T> {
+    let mut node = node;
+
+    while let Some(right) = node.borrow(token).right() {
+        node = right;
+    }
+
+    node
+}
+
+/// Order-statistic descent from `node` to the node whose span covers
+/// `index`, analogous to [`IndexedRBTree::get`] but returning the node
+/// itself rather than its value.
+fn descend_to_index<'a, 't, T>(
+    node: &'t NodeRc<'a, T>,
+    mut index: usize,
+    token: &'t GhostToken<'a>,
+) -> &'t NodeRc<'a, T> {
+    let mut node = node;
+
+    loop {
+        let n = node.borrow(token);
+        let left_size = n.left().map(|l| l.borrow(token).size).unwrap_or(0);
+
+        if index < left_size {
+            node = n.left().unwrap();
+        } else if index < left_size + n.weight {
+            return node;
+        } else {
+            index -= left_size + n.weight;
+            node = n.right().unwrap();
+        }
+    }
+}
+
+/// The in-order successor of `node`: if it has a right child, the leftmost
+/// node of that subtree; otherwise the nearest ancestor of which `node` is
+/// in the left subtree.
+fn successor<'a, 't, T>(
+    node: &'t NodeRc<'a, T>,
+    token: &'t GhostToken<'a>,
+) -> Option<&'t NodeRc<'a, T>> {
+    let n = node.borrow(token);
+
+    if let Some(right) = n.right() {
+        return Some(leftmost(right, token));
+    }
+
+    let mut current = node;
+
+    while let Some(parent) = current.borrow(token).parent() {
+        let is_left_child = parent
+            .borrow(token)
+            .left()
+            .map(|l| std::ptr::eq(l.as_ptr(), current.as_ptr()))
+            .unwrap_or(false);
+
+        if is_left_child {
+            return Some(parent);
+        }
+
+        current = parent;
+    }
+
+    None
+}
+
+/// The in-order predecessor of `node`, symmetric to [`successor`].
+fn predecessor<'a, 't, T>(
+    node: &'t NodeRc<'a, T>,
+    token: &'t GhostToken<'a>,
+) -> Option<&'t NodeRc<'a, T>> {
+    let n = node.borrow(token);
+
+    if let Some(left) = n.left() {
+        return Some(rightmost(left, token));
+    }
+
+    let mut current = node;
+
+    while let Some(parent) = current.borrow(token).parent() {
+        let is_right_child = parent
+            .borrow(token)
+            .right()
+            .map(|r| std::ptr::eq(r.as_ptr(), current.as_ptr()))
+            .unwrap_or(false);
+
+        if is_right_child {
+            return Some(parent);
+        }
+
+        current = parent;
+    }
+
+    None
+}
+
+/// In-order iterator over shared references, returned by
+/// [`IndexedRBTree::iter`].
+pub struct Iter<'a, 't, T> {
+    current: Option<&'t NodeRc<'a, T>>,
+    token: &'t GhostToken<'a>,
+}
+
+impl<'a, 't, T> Iterator for Iter<'a, 't, T> {
+    type Item = &'t T;
+
+    fn next(&mut self) -> Option<&'t T> {
+        let node = self.current.take()?;
+        self.current = successor(node, self.token);
+        Some(&node.borrow(self.token).value)
+    }
+}
+
+/// In-order iterator over exclusive references, returned by
+/// [`IndexedRBTree::iter_mut`].
+pub struct IterMut<'a, 't, T> {
+    current: Option<&'t NodeRc<'a, T>>,
+    token: &'t GhostToken<'a>,
+}
+
+impl<'a, 't, T> Iterator for IterMut<'a, 't, T> {
+    type Item = &'t mut T;
+
+    fn next(&mut self) -> Option<&'t mut T> {
+        let node = self.current.take()?;
+        self.current = successor(node, self.token);
+
+        // SAFETY: in-order traversal visits each node exactly once, so the
+        // `&mut T` produced here never aliases a reference handed out by a
+        // previous or future call to `next`.
+        Some(unsafe { &mut (*node.as_ptr()).value })
+    }
+}
+
+/// A seekable, non-owning reference into a tree position, returned by
+/// [`IndexedRBTree::cursor`]. Moves along the threaded parent/child links in
+/// O(1) amortized per step rather than re-descending from the root.
+pub struct Cursor<'a, 't, T> {
+    node: Option<&'t NodeRc<'a, T>>,
+    token: &'t GhostToken<'a>,
+}
+
+impl<'a, 't, T> Cursor<'a, 't, T> {
+    /// The value at the cursor's current position, or `None` if the cursor
+    /// is past either end of the tree.
+    pub fn value(&self) -> Option<&'t T> {
+        self.node.map(|node| &node.borrow(self.token).value)
+    }
+
+    pub fn move_next(&mut self) {
+        self.node = self.node.and_then(|node| successor(node, self.token));
+    }
+
+    pub fn move_prev(&mut self) {
+        self.node = self.node.and_then(|node| predecessor(node, self.token));
+    }
+}
+
+/// Mutable counterpart of [`Cursor`], returned by
+/// [`IndexedRBTree::cursor_mut`].
+pub struct CursorMut<'a, 't, T> {
+    node: Option<&'t NodeRc<'a, T>>,
+    token: &'t GhostToken<'a>,
+}
+
+impl<'a, 't, T> CursorMut<'a, 't, T> {
+    pub fn value(&self) -> Option<&T> {
+        self.node.map(|node| &node.borrow(self.token).value)
+    }
+
+    /// The value at the cursor's current position, or `None` if the cursor
+    /// is past either end of the tree.
+    pub fn value_mut(&mut self) -> Option<&'t mut T> {
+        self.node.map(|node| {
+            // SAFETY: the cursor holds at most one node at a time, so this
+            // exclusive reference cannot alias another live reference.
+            unsafe { &mut (*node.as_ptr()).value }
+        })
+    }
+
+    pub fn move_next(&mut self) {
+        self.node = self.node.and_then(|node| successor(node, self.token));
+    }
+
+    pub fn move_prev(&mut self) {
+        self.node = self.node.and_then(|node| predecessor(node, self.token));
     }
 }
 
@@ -243,4 +1723,148 @@ mod test {
             // assert_eq!(tree.back(&token), Some(&1));
         });
     }
+
+    #[test]
+    fn get_empty_test() {
+        GhostToken::new(|token| {
+            let tree: IndexedRBTree<usize> = IndexedRBTree::new();
+            assert_eq!(tree.get(0, &token), None);
+        });
+    }
+
+    #[test]
+    fn get_singleton_test() {
+        GhostToken::new(|mut token| {
+            let tree = IndexedRBTree::singleton(42, 3, &mut token);
+            assert_eq!(tree.get(0, &token), Some((&42, 0)));
+            assert_eq!(tree.get(2, &token), Some((&42, 2)));
+            assert_eq!(tree.get(3, &token), None);
+        });
+    }
+
+    #[test]
+    fn iter_singleton_test() {
+        GhostToken::new(|mut token| {
+            let tree = IndexedRBTree::singleton(42, 3, &mut token);
+            let values: Vec<&usize> = tree.iter(&token).collect();
+            assert_eq!(values, vec![&42]);
+        });
+    }
+
+    #[test]
+    fn cursor_singleton_test() {
+        GhostToken::new(|mut token| {
+            let tree = IndexedRBTree::singleton(42, 3, &mut token);
+
+            let mut cursor = tree.cursor(1, &token);
+            assert_eq!(cursor.value(), Some(&42));
+
+            cursor.move_next();
+            assert_eq!(cursor.value(), None);
+
+            let cursor = tree.cursor(3, &token);
+            assert_eq!(cursor.value(), None);
+        });
+    }
+
+    #[test]
+    fn insert_builds_positional_order() {
+        GhostToken::new(|mut token| {
+            let mut tree: IndexedRBTree<usize> = IndexedRBTree::new();
+
+            for (i, value) in [5, 1, 4, 2, 3].into_iter().enumerate() {
+                tree.insert(value, 1, i, &mut token);
+            }
+
+            assert_eq!(tree.len(), 5);
+            let values: Vec<&usize> = tree.iter(&token).collect();
+            assert_eq!(values, vec![&5, &1, &4, &2, &3]);
+            assert_eq!(tree.get(0, &token), Some((&5, 0)));
+            assert_eq!(tree.get(4, &token), Some((&3, 0)));
+        });
+    }
+
+    #[test]
+    fn insert_then_remove_round_trips() {
+        GhostToken::new(|mut token| {
+            let mut tree: IndexedRBTree<usize> = IndexedRBTree::new();
+
+            for (i, value) in [10, 20, 30, 40, 50].into_iter().enumerate() {
+                tree.insert(value, 1, i, &mut token);
+            }
+
+            assert_eq!(tree.remove(2, &mut token), Some(30));
+            assert_eq!(tree.len(), 4);
+
+            let values: Vec<&usize> = tree.iter(&token).collect();
+            assert_eq!(values, vec![&10, &20, &40, &50]);
+            assert_eq!(tree.remove(10, &mut token), None);
+        });
+    }
+
+    #[test]
+    fn split_at_then_concat_round_trips() {
+        GhostToken::new(|mut token| {
+            let mut tree: IndexedRBTree<usize> = IndexedRBTree::new();
+            for i in 0..10 {
+                tree.insert(i, 1, i, &mut token);
+            }
+
+            let right = tree.split_at(4, |v, _| (v, 0), &mut token);
+            assert_eq!(tree.len(), 4);
+            assert_eq!(right.len(), 6);
+
+            let left_values: Vec<&usize> = tree.iter(&token).collect();
+            let right_values: Vec<&usize> = right.iter(&token).collect();
+            assert_eq!(left_values, vec![&0, &1, &2, &3]);
+            assert_eq!(right_values, vec![&4, &5, &6, &7, &8, &9]);
+
+            tree.concat(right, &mut token);
+            assert_eq!(tree.len(), 10);
+            let values: Vec<&usize> = tree.iter(&token).collect();
+            assert_eq!(values, (0..10).collect::<Vec<_>>().iter().collect::<Vec<_>>());
+        });
+    }
+
+    #[test]
+    fn split_at_straddles_a_span() {
+        GhostToken::new(|mut token| {
+            let mut tree: IndexedRBTree<&'static str> = IndexedRBTree::new();
+            tree.insert("hello", 5, 0, &mut token);
+            tree.insert("world", 5, 5, &mut token);
+
+            let right = tree.split_at(3, |v, at| v.split_at(at), &mut token);
+
+            assert_eq!(tree.len(), 1);
+            assert_eq!(right.len(), 2);
+            let left_values: Vec<&&str> = tree.iter(&token).collect();
+            let right_values: Vec<&&str> = right.iter(&token).collect();
+            assert_eq!(left_values, vec![&"hel"]);
+            assert_eq!(right_values, vec![&"lo", &"world"]);
+        });
+    }
+
+    #[test]
+    fn clear_empties_a_deep_tree() {
+        GhostToken::new(|mut token| {
+            let mut tree: IndexedRBTree<usize> = IndexedRBTree::new();
+            for i in 0..1000 {
+                tree.insert(i, 1, i, &mut token);
+            }
+
+            tree.clear(&mut token);
+            assert_eq!(tree.len(), 0);
+            assert!(tree.is_empty());
+        });
+    }
+
+    #[test]
+    fn drop_tears_down_without_clear() {
+        GhostToken::new(|mut token| {
+            let mut tree: IndexedRBTree<usize> = IndexedRBTree::new();
+            for i in 0..1000 {
+                tree.insert(i, 1, i, &mut token);
+            }
+        });
+    }
 }